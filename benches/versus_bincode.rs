@@ -126,6 +126,639 @@ impl io::Read for UnsafeReader {
     }
 }
 
+use peek_poke_benchs::checked::{peek_from_checked, SizeLimit};
+
+// A hand-rolled LEB128 varint encoding for `CommonItemProperties`'s id
+// fields, which are stored at full fixed width by the derived `PeekPoke`
+// impl even when the runtime value is tiny.
+mod varint {
+    use std::mem::size_of;
+
+    /// Unsigned integers are encoded 7 bits at a time, little-endian, with
+    /// the high bit of each byte set when more bytes follow. Signed
+    /// integers are zigzag-encoded first so small negatives stay short.
+    pub trait VarInt: Copy {
+        /// `ceil(bits / 7)`: the number of bytes needed in the worst case,
+        /// where every output byte only carries 7 payload bits.
+        const MAX_SIZE: usize;
+
+        fn write_varint(self, buf: &mut Vec<u8>);
+        /// Returns the decoded value and the number of bytes consumed.
+        fn read_varint(buf: &[u8]) -> (Self, usize);
+    }
+
+    macro_rules! impl_varint_unsigned {
+        ($ty:ty) => {
+            impl VarInt for $ty {
+                const MAX_SIZE: usize = (size_of::<$ty>() * 8 + 6) / 7;
+
+                fn write_varint(self, buf: &mut Vec<u8>) {
+                    let mut v = self;
+                    loop {
+                        let byte = (v & 0x7f) as u8;
+                        v >>= 7;
+                        if v == 0 {
+                            buf.push(byte);
+                            break;
+                        }
+                        buf.push(byte | 0x80);
+                    }
+                }
+
+                fn read_varint(buf: &[u8]) -> (Self, usize) {
+                    let mut result: $ty = 0;
+                    let mut shift = 0u32;
+                    for (i, &byte) in buf.iter().enumerate() {
+                        assert!(
+                            shift < (size_of::<$ty>() * 8) as u32,
+                            "varint overflows {}",
+                            stringify!($ty)
+                        );
+                        result |= ((byte & 0x7f) as $ty) << shift;
+                        if byte & 0x80 == 0 {
+                            return (result, i + 1);
+                        }
+                        shift += 7;
+                    }
+                    panic!("truncated varint");
+                }
+            }
+        };
+    }
+
+    impl_varint_unsigned!(u16);
+    impl_varint_unsigned!(u32);
+    impl_varint_unsigned!(u64);
+    impl_varint_unsigned!(usize);
+
+    macro_rules! impl_varint_signed {
+        ($ty:ty, $unsigned:ty) => {
+            impl VarInt for $ty {
+                const MAX_SIZE: usize = (size_of::<$ty>() * 8 + 6) / 7;
+
+                fn write_varint(self, buf: &mut Vec<u8>) {
+                    let bits = (size_of::<$ty>() * 8 - 1) as $ty;
+                    let zigzagged = ((self << 1) ^ (self >> bits)) as $unsigned;
+                    zigzagged.write_varint(buf);
+                }
+
+                fn read_varint(buf: &[u8]) -> (Self, usize) {
+                    let (zigzagged, consumed): ($unsigned, usize) = VarInt::read_varint(buf);
+                    let magnitude = (zigzagged >> 1) as $ty;
+                    let sign = -((zigzagged & 1) as $ty);
+                    (magnitude ^ sign, consumed)
+                }
+            }
+        };
+    }
+
+    impl_varint_signed!(i16, u16);
+    impl_varint_signed!(i32, u32);
+    impl_varint_signed!(i64, u64);
+    impl_varint_signed!(isize, usize);
+}
+
+use varint::VarInt;
+
+/// Upper bound on the varint-encoded size of `CommonItemProperties`: the
+/// `Rect` stays fixed-width (it's all `f32`), the id fields are bounded by
+/// their `VarInt::MAX_SIZE`, and the enum discriminant / `Option` flag /
+/// `bool` are each one byte.
+fn varint_max_size() -> usize {
+    let spatial_id = usize::MAX_SIZE + u32::MAX_SIZE * 2;
+    let clip_id = 1 + usize::MAX_SIZE.max(u64::MAX_SIZE) + u32::MAX_SIZE * 2;
+    let hit_info = 1 + u64::MAX_SIZE + u16::MAX_SIZE;
+    Rect::max_size() + spatial_id + clip_id + hit_info + 1
+}
+
+fn varint_poke_into(item: &CommonItemProperties, buf: &mut Vec<u8>) {
+    unsafe {
+        let mut rect_bytes = [0u8; 16];
+        item.clip_rect.poke_into(rect_bytes.as_mut_ptr());
+        buf.extend_from_slice(&rect_bytes);
+    }
+
+    item.spatial_id.0.write_varint(buf);
+    (item.spatial_id.1).0.write_varint(buf);
+    (item.spatial_id.1).1.write_varint(buf);
+
+    match item.clip_id {
+        ClipId::Clip(index, pipeline_id) => {
+            buf.push(0);
+            index.write_varint(buf);
+            pipeline_id.0.write_varint(buf);
+            pipeline_id.1.write_varint(buf);
+        }
+        ClipId::ClipChain(chain_id) => {
+            buf.push(1);
+            chain_id.0.write_varint(buf);
+            (chain_id.1).0.write_varint(buf);
+            (chain_id.1).1.write_varint(buf);
+        }
+    }
+
+    match item.hit_info {
+        Some(tag) => {
+            buf.push(1);
+            tag.0.write_varint(buf);
+            tag.1.write_varint(buf);
+        }
+        None => buf.push(0),
+    }
+
+    buf.push(item.is_backface_visible as u8);
+}
+
+fn varint_peek_from(buf: &[u8]) -> (CommonItemProperties, &[u8]) {
+    let mut clip_rect = Rect::default();
+    let rect_end = unsafe { clip_rect.peek_from(buf.as_ptr()) };
+    let mut offset = rect_end as usize - buf.as_ptr() as usize;
+
+    let (spatial_0, n) = usize::read_varint(&buf[offset..]);
+    offset += n;
+    let (spatial_1_0, n) = u32::read_varint(&buf[offset..]);
+    offset += n;
+    let (spatial_1_1, n) = u32::read_varint(&buf[offset..]);
+    offset += n;
+    let spatial_id = SpatialId(spatial_0, PipelineId(spatial_1_0, spatial_1_1));
+
+    let discriminant = buf[offset];
+    offset += 1;
+    let clip_id = if discriminant == 0 {
+        let (index, n) = usize::read_varint(&buf[offset..]);
+        offset += n;
+        let (p0, n) = u32::read_varint(&buf[offset..]);
+        offset += n;
+        let (p1, n) = u32::read_varint(&buf[offset..]);
+        offset += n;
+        ClipId::Clip(index, PipelineId(p0, p1))
+    } else {
+        let (chain_0, n) = u64::read_varint(&buf[offset..]);
+        offset += n;
+        let (p0, n) = u32::read_varint(&buf[offset..]);
+        offset += n;
+        let (p1, n) = u32::read_varint(&buf[offset..]);
+        offset += n;
+        ClipId::ClipChain(ClipChainId(chain_0, PipelineId(p0, p1)))
+    };
+
+    let has_hit_info = buf[offset];
+    offset += 1;
+    let hit_info = if has_hit_info != 0 {
+        let (tag_0, n) = u64::read_varint(&buf[offset..]);
+        offset += n;
+        let (tag_1, n) = u16::read_varint(&buf[offset..]);
+        offset += n;
+        Some(ItemTag(tag_0, tag_1))
+    } else {
+        None
+    };
+
+    let is_backface_visible = buf[offset] != 0;
+    offset += 1;
+
+    (
+        CommonItemProperties {
+            clip_rect,
+            spatial_id,
+            clip_id,
+            hit_info,
+            is_backface_visible,
+        },
+        &buf[offset..],
+    )
+}
+
+// `poke_into`/`peek_from` is a native-endian `memcpy`, so this byte-swaps
+// integer and float primitives on write/read when `Endian` differs from the
+// host's, keeping the same fixed wire layout.
+mod endian {
+    use std::mem::size_of;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Endian {
+        Native,
+        Little,
+        Big,
+    }
+
+    pub trait EndianCodec: Sized {
+        const SIZE: usize;
+
+        fn write_endian(self, endian: Endian, buf: &mut Vec<u8>);
+        fn read_endian(buf: &[u8], endian: Endian) -> Self;
+    }
+
+    macro_rules! impl_endian_int {
+        ($ty:ty) => {
+            impl EndianCodec for $ty {
+                const SIZE: usize = size_of::<$ty>();
+
+                fn write_endian(self, endian: Endian, buf: &mut Vec<u8>) {
+                    let bytes = match endian {
+                        Endian::Native => self.to_ne_bytes(),
+                        Endian::Little => self.to_le_bytes(),
+                        Endian::Big => self.to_be_bytes(),
+                    };
+                    buf.extend_from_slice(&bytes);
+                }
+
+                fn read_endian(buf: &[u8], endian: Endian) -> Self {
+                    let mut bytes = [0u8; size_of::<$ty>()];
+                    bytes.copy_from_slice(&buf[..bytes.len()]);
+                    match endian {
+                        Endian::Native => <$ty>::from_ne_bytes(bytes),
+                        Endian::Little => <$ty>::from_le_bytes(bytes),
+                        Endian::Big => <$ty>::from_be_bytes(bytes),
+                    }
+                }
+            }
+        };
+    }
+
+    impl_endian_int!(u16);
+    impl_endian_int!(u32);
+    impl_endian_int!(u64);
+    impl_endian_int!(usize);
+
+    impl EndianCodec for f32 {
+        const SIZE: usize = 4;
+
+        fn write_endian(self, endian: Endian, buf: &mut Vec<u8>) {
+            self.to_bits().write_endian(endian, buf);
+        }
+
+        fn read_endian(buf: &[u8], endian: Endian) -> Self {
+            f32::from_bits(u32::read_endian(buf, endian))
+        }
+    }
+}
+
+use endian::{Endian, EndianCodec};
+
+fn endian_poke_into(item: &CommonItemProperties, endian: Endian, buf: &mut Vec<u8>) {
+    item.clip_rect.point.x.write_endian(endian, buf);
+    item.clip_rect.point.y.write_endian(endian, buf);
+    item.clip_rect.size.w.write_endian(endian, buf);
+    item.clip_rect.size.h.write_endian(endian, buf);
+
+    item.spatial_id.0.write_endian(endian, buf);
+    (item.spatial_id.1).0.write_endian(endian, buf);
+    (item.spatial_id.1).1.write_endian(endian, buf);
+
+    match item.clip_id {
+        ClipId::Clip(index, pipeline_id) => {
+            buf.push(0);
+            index.write_endian(endian, buf);
+            pipeline_id.0.write_endian(endian, buf);
+            pipeline_id.1.write_endian(endian, buf);
+        }
+        ClipId::ClipChain(chain_id) => {
+            buf.push(1);
+            chain_id.0.write_endian(endian, buf);
+            (chain_id.1).0.write_endian(endian, buf);
+            (chain_id.1).1.write_endian(endian, buf);
+        }
+    }
+
+    match item.hit_info {
+        Some(tag) => {
+            buf.push(1);
+            tag.0.write_endian(endian, buf);
+            tag.1.write_endian(endian, buf);
+        }
+        None => buf.push(0),
+    }
+
+    buf.push(item.is_backface_visible as u8);
+}
+
+fn endian_peek_from(buf: &[u8], endian: Endian) -> (CommonItemProperties, &[u8]) {
+    let mut offset = 0;
+    let mut read_f32 = |buf: &[u8], offset: &mut usize| {
+        let v = f32::read_endian(&buf[*offset..], endian);
+        *offset += f32::SIZE;
+        v
+    };
+    let clip_rect = Rect {
+        point: Point {
+            x: read_f32(buf, &mut offset),
+            y: read_f32(buf, &mut offset),
+        },
+        size: Size {
+            w: read_f32(buf, &mut offset),
+            h: read_f32(buf, &mut offset),
+        },
+    };
+
+    let spatial_0 = usize::read_endian(&buf[offset..], endian);
+    offset += usize::SIZE;
+    let spatial_1_0 = u32::read_endian(&buf[offset..], endian);
+    offset += u32::SIZE;
+    let spatial_1_1 = u32::read_endian(&buf[offset..], endian);
+    offset += u32::SIZE;
+    let spatial_id = SpatialId(spatial_0, PipelineId(spatial_1_0, spatial_1_1));
+
+    let discriminant = buf[offset];
+    offset += 1;
+    let clip_id = if discriminant == 0 {
+        let index = usize::read_endian(&buf[offset..], endian);
+        offset += usize::SIZE;
+        let p0 = u32::read_endian(&buf[offset..], endian);
+        offset += u32::SIZE;
+        let p1 = u32::read_endian(&buf[offset..], endian);
+        offset += u32::SIZE;
+        ClipId::Clip(index, PipelineId(p0, p1))
+    } else {
+        let chain_0 = u64::read_endian(&buf[offset..], endian);
+        offset += u64::SIZE;
+        let p0 = u32::read_endian(&buf[offset..], endian);
+        offset += u32::SIZE;
+        let p1 = u32::read_endian(&buf[offset..], endian);
+        offset += u32::SIZE;
+        ClipId::ClipChain(ClipChainId(chain_0, PipelineId(p0, p1)))
+    };
+
+    let has_hit_info = buf[offset];
+    offset += 1;
+    let hit_info = if has_hit_info != 0 {
+        let tag_0 = u64::read_endian(&buf[offset..], endian);
+        offset += u64::SIZE;
+        let tag_1 = u16::read_endian(&buf[offset..], endian);
+        offset += u16::SIZE;
+        Some(ItemTag(tag_0, tag_1))
+    } else {
+        None
+    };
+
+    let is_backface_visible = buf[offset] != 0;
+    offset += 1;
+
+    (
+        CommonItemProperties {
+            clip_rect,
+            spatial_id,
+            clip_id,
+            hit_info,
+            is_backface_visible,
+        },
+        &buf[offset..],
+    )
+}
+
+// Packs `is_backface_visible`, `hit_info`'s present/absent flag, and
+// `ClipId`'s discriminant into a dense bit stream, leaving the remaining
+// fixed-width numeric fields byte-aligned.
+use peek_poke_benchs::bitpack::BitWriter;
+
+fn bitpack_poke_into(item: &CommonItemProperties) -> Vec<u8> {
+    let mut w = BitWriter::new();
+
+    w.write_bool(item.is_backface_visible);
+    w.write_bits(if matches!(item.clip_id, ClipId::ClipChain(_)) { 1 } else { 0 }, 1);
+    w.write_bool(item.hit_info.is_some());
+
+    let mut rect_bytes = [0u8; 16];
+    unsafe {
+        item.clip_rect.poke_into(rect_bytes.as_mut_ptr());
+    }
+    w.write_raw_bytes(&rect_bytes);
+
+    w.write_raw_bytes(&item.spatial_id.0.to_ne_bytes());
+    w.write_raw_bytes(&(item.spatial_id.1).0.to_ne_bytes());
+    w.write_raw_bytes(&(item.spatial_id.1).1.to_ne_bytes());
+
+    match item.clip_id {
+        ClipId::Clip(index, pipeline_id) => {
+            w.write_raw_bytes(&index.to_ne_bytes());
+            w.write_raw_bytes(&pipeline_id.0.to_ne_bytes());
+            w.write_raw_bytes(&pipeline_id.1.to_ne_bytes());
+        }
+        ClipId::ClipChain(chain_id) => {
+            w.write_raw_bytes(&chain_id.0.to_ne_bytes());
+            w.write_raw_bytes(&(chain_id.1).0.to_ne_bytes());
+            w.write_raw_bytes(&(chain_id.1).1.to_ne_bytes());
+        }
+    }
+
+    if let Some(tag) = item.hit_info {
+        w.write_raw_bytes(&tag.0.to_ne_bytes());
+        w.write_raw_bytes(&tag.1.to_ne_bytes());
+    }
+
+    w.finish()
+}
+
+fn bitpack_peek_from(bytes: &[u8]) -> CommonItemProperties {
+    use peek_poke_benchs::bitpack::BitReader;
+
+    let mut r = BitReader::new(bytes);
+
+    let is_backface_visible = r.read_bool();
+    let clip_id_is_chain = r.read_bits(1) != 0;
+    let has_hit_info = r.read_bool();
+
+    let mut clip_rect = Rect::default();
+    unsafe {
+        clip_rect.peek_from(r.read_raw_bytes(16).as_ptr());
+    }
+
+    let spatial_index = usize::from_ne_bytes(r.read_raw_bytes(8).try_into().unwrap());
+    let spatial_pipeline = PipelineId(
+        u32::from_ne_bytes(r.read_raw_bytes(4).try_into().unwrap()),
+        u32::from_ne_bytes(r.read_raw_bytes(4).try_into().unwrap()),
+    );
+    let spatial_id = SpatialId(spatial_index, spatial_pipeline);
+
+    let clip_id = if clip_id_is_chain {
+        let chain_index = u64::from_ne_bytes(r.read_raw_bytes(8).try_into().unwrap());
+        let pipeline_id = PipelineId(
+            u32::from_ne_bytes(r.read_raw_bytes(4).try_into().unwrap()),
+            u32::from_ne_bytes(r.read_raw_bytes(4).try_into().unwrap()),
+        );
+        ClipId::ClipChain(ClipChainId(chain_index, pipeline_id))
+    } else {
+        let index = usize::from_ne_bytes(r.read_raw_bytes(8).try_into().unwrap());
+        let pipeline_id = PipelineId(
+            u32::from_ne_bytes(r.read_raw_bytes(4).try_into().unwrap()),
+            u32::from_ne_bytes(r.read_raw_bytes(4).try_into().unwrap()),
+        );
+        ClipId::Clip(index, pipeline_id)
+    };
+
+    let hit_info = if has_hit_info {
+        let tag = u64::from_ne_bytes(r.read_raw_bytes(8).try_into().unwrap());
+        let tag_sub = u16::from_ne_bytes(r.read_raw_bytes(2).try_into().unwrap());
+        Some(ItemTag(tag, tag_sub))
+    } else {
+        None
+    };
+
+    CommonItemProperties {
+        clip_rect,
+        spatial_id,
+        clip_id,
+        hit_info,
+        is_backface_visible,
+    }
+}
+
+// `Vec<T>` has no static `max_size()`, so poking one is a length prefix
+// followed by each element in turn, and peeking reads the count first and
+// then that many elements, advancing through the buffer as it goes. This
+// lets the benchmark measure batch ("display list") throughput instead of
+// just a single struct.
+fn poke_vec_into(items: &[CommonItemProperties], buf: &mut Vec<u8>) {
+    buf.extend_from_slice(&(items.len() as u64).to_ne_bytes());
+    for item in items {
+        let start = buf.len();
+        buf.resize(start + CommonItemProperties::max_size(), 0);
+        let end_ptr = unsafe { item.poke_into(buf[start..].as_mut_ptr()) };
+        let written = end_ptr as usize - buf[start..].as_ptr() as usize;
+        buf.truncate(start + written);
+    }
+}
+
+fn peek_vec_from(buf: &[u8]) -> (Vec<CommonItemProperties>, &[u8]) {
+    let mut len_bytes = [0u8; 8];
+    len_bytes.copy_from_slice(&buf[..8]);
+    let len = u64::from_ne_bytes(len_bytes) as usize;
+    let mut offset = 8;
+    let mut items = Vec::with_capacity(len);
+    for _ in 0..len {
+        let mut item = CommonItemProperties::default();
+        let end_ptr = unsafe { item.peek_from(buf[offset..].as_ptr()) };
+        offset += end_ptr as usize - buf[offset..].as_ptr() as usize;
+        items.push(item);
+    }
+    (items, &buf[offset..])
+}
+
+fn display_list() -> Vec<CommonItemProperties> {
+    (0..64)
+        .map(|i| CommonItemProperties {
+            clip_rect: Rect {
+                point: Point { x: 1.0, y: 2.0 },
+                size: Size { w: 4.0, h: 5.0 },
+            },
+            clip_id: ClipId::Clip(i as usize, PipelineId(1, 2)),
+            spatial_id: SpatialId(3, PipelineId(4, 5)),
+            hit_info: None,
+            is_backface_visible: true,
+        })
+        .collect()
+}
+
+// Skips fields an older decoder doesn't recognize and falls back to
+// `Default` for fields a newer decoder expected but didn't find, instead
+// of the fixed positional layout `poke_into`/`peek_from` require.
+use peek_poke_benchs::described::{DescribedReader, DescribedWriter};
+
+fn poke_into_described(item: &CommonItemProperties) -> Vec<u8> {
+    let mut w = DescribedWriter::new();
+
+    let mut rect_bytes = [0u8; 16];
+    unsafe {
+        item.clip_rect.poke_into(rect_bytes.as_mut_ptr());
+    }
+    w.write_field("clip_rect", &rect_bytes);
+
+    let mut spatial_bytes = Vec::new();
+    spatial_bytes.extend_from_slice(&item.spatial_id.0.to_ne_bytes());
+    spatial_bytes.extend_from_slice(&(item.spatial_id.1).0.to_ne_bytes());
+    spatial_bytes.extend_from_slice(&(item.spatial_id.1).1.to_ne_bytes());
+    w.write_field("spatial_id", &spatial_bytes);
+
+    // The variant itself is carried by which of these two field names is
+    // present, so it goes through the symbol table like any other field
+    // rather than a hand-rolled discriminant byte.
+    match item.clip_id {
+        ClipId::Clip(index, pipeline_id) => {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&index.to_ne_bytes());
+            bytes.extend_from_slice(&pipeline_id.0.to_ne_bytes());
+            bytes.extend_from_slice(&pipeline_id.1.to_ne_bytes());
+            w.write_field("Clip", &bytes);
+        }
+        ClipId::ClipChain(chain_id) => {
+            let mut bytes = Vec::new();
+            bytes.extend_from_slice(&chain_id.0.to_ne_bytes());
+            bytes.extend_from_slice(&(chain_id.1).0.to_ne_bytes());
+            bytes.extend_from_slice(&(chain_id.1).1.to_ne_bytes());
+            w.write_field("ClipChain", &bytes);
+        }
+    }
+
+    if let Some(tag) = item.hit_info {
+        let mut tag_bytes = Vec::new();
+        tag_bytes.extend_from_slice(&tag.0.to_ne_bytes());
+        tag_bytes.extend_from_slice(&tag.1.to_ne_bytes());
+        w.write_field("hit_info", &tag_bytes);
+    }
+
+    w.write_field("is_backface_visible", &[item.is_backface_visible as u8]);
+
+    w.finish()
+}
+
+fn peek_from_described(bytes: &[u8]) -> CommonItemProperties {
+    let r = DescribedReader::parse(bytes);
+
+    let clip_rect = match r.field("clip_rect") {
+        Some(bytes) => {
+            let mut rect = Rect::default();
+            unsafe {
+                rect.peek_from(bytes.as_ptr());
+            }
+            rect
+        }
+        None => Rect::default(),
+    };
+
+    let spatial_id = match r.field("spatial_id") {
+        Some(bytes) => {
+            let a = u64::from_ne_bytes(bytes[0..8].try_into().unwrap()) as usize;
+            let b = u32::from_ne_bytes(bytes[8..12].try_into().unwrap());
+            let c = u32::from_ne_bytes(bytes[12..16].try_into().unwrap());
+            SpatialId(a, PipelineId(b, c))
+        }
+        None => SpatialId::default(),
+    };
+
+    let clip_id = if let Some(bytes) = r.field("Clip") {
+        let index = u64::from_ne_bytes(bytes[0..8].try_into().unwrap()) as usize;
+        let b = u32::from_ne_bytes(bytes[8..12].try_into().unwrap());
+        let c = u32::from_ne_bytes(bytes[12..16].try_into().unwrap());
+        ClipId::Clip(index, PipelineId(b, c))
+    } else if let Some(bytes) = r.field("ClipChain") {
+        let chain_0 = u64::from_ne_bytes(bytes[0..8].try_into().unwrap());
+        let b = u32::from_ne_bytes(bytes[8..12].try_into().unwrap());
+        let c = u32::from_ne_bytes(bytes[12..16].try_into().unwrap());
+        ClipId::ClipChain(ClipChainId(chain_0, PipelineId(b, c)))
+    } else {
+        ClipId::default()
+    };
+
+    let hit_info = r.field("hit_info").map(|bytes| {
+        let tag_0 = u64::from_ne_bytes(bytes[0..8].try_into().unwrap());
+        let tag_1 = u16::from_ne_bytes(bytes[8..10].try_into().unwrap());
+        ItemTag(tag_0, tag_1)
+    });
+
+    let is_backface_visible = r
+        .field("is_backface_visible")
+        .map(|bytes| bytes[0] != 0)
+        .unwrap_or_default();
+
+    CommonItemProperties {
+        clip_rect,
+        spatial_id,
+        clip_id,
+        hit_info,
+        is_backface_visible,
+    }
+}
+
 #[allow(unused_must_use)]
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench(
@@ -165,6 +798,92 @@ fn criterion_benchmark(c: &mut Criterion) {
                 };
                 black_box(bincode::encode_into_slice(black_box(&my_struct), &mut buffer.as_mut_slice(), config).unwrap());
             })
+        })
+        .with_function("peek_poke::varint", |b| {
+            let mut buffer = Vec::with_capacity(varint_max_size());
+            b.iter(|| {
+                buffer.clear();
+                let my_struct = CommonItemProperties {
+                    clip_rect: Rect {
+                        point: Point { x: 1.0, y: 2.0 },
+                        size: Size { w: 4.0, h: 5.0 },
+                    },
+                    clip_id: ClipId::Clip(5, PipelineId(1, 2)),
+                    spatial_id: SpatialId(3, PipelineId(4, 5)),
+                    hit_info: None,
+                    is_backface_visible: true,
+                };
+                varint_poke_into(black_box(&my_struct), &mut buffer);
+                black_box(&buffer);
+            })
+        })
+        .with_function("peek_poke::poke_into (big-endian)", |b| {
+            let mut buffer = Vec::with_capacity(CommonItemProperties::max_size());
+            b.iter(|| {
+                buffer.clear();
+                let my_struct = CommonItemProperties {
+                    clip_rect: Rect {
+                        point: Point { x: 1.0, y: 2.0 },
+                        size: Size { w: 4.0, h: 5.0 },
+                    },
+                    clip_id: ClipId::Clip(5, PipelineId(1, 2)),
+                    spatial_id: SpatialId(3, PipelineId(4, 5)),
+                    hit_info: None,
+                    is_backface_visible: true,
+                };
+                endian_poke_into(black_box(&my_struct), Endian::Big, &mut buffer);
+                black_box(&buffer);
+            })
+        })
+        .with_function("peek_poke::bitpack", |b| {
+            b.iter(|| {
+                let my_struct = CommonItemProperties {
+                    clip_rect: Rect {
+                        point: Point { x: 1.0, y: 2.0 },
+                        size: Size { w: 4.0, h: 5.0 },
+                    },
+                    clip_id: ClipId::Clip(5, PipelineId(1, 2)),
+                    spatial_id: SpatialId(3, PipelineId(4, 5)),
+                    hit_info: None,
+                    is_backface_visible: true,
+                };
+                black_box(bitpack_poke_into(black_box(&my_struct)));
+            })
+        })
+        .with_function("peek_poke::poke_into (display list)", |b| {
+            let items = display_list();
+            let mut buffer = Vec::with_capacity(8 + items.len() * CommonItemProperties::max_size());
+            b.iter(|| {
+                buffer.clear();
+                poke_vec_into(black_box(&items), &mut buffer);
+                black_box(&buffer);
+            })
+        })
+        .with_function("bincode::serialize (display list)", |b| {
+            let items = display_list();
+            let mut buffer = vec![0u8; 8 + items.len() * CommonItemProperties::max_size()];
+            let config = bincode::config::Configuration::legacy();
+            b.iter(|| {
+                black_box(
+                    bincode::encode_into_slice(black_box(&items), &mut buffer.as_mut_slice(), config)
+                        .unwrap(),
+                );
+            })
+        })
+        .with_function("peek_poke::poke_into_described", |b| {
+            b.iter(|| {
+                let my_struct = CommonItemProperties {
+                    clip_rect: Rect {
+                        point: Point { x: 1.0, y: 2.0 },
+                        size: Size { w: 4.0, h: 5.0 },
+                    },
+                    clip_id: ClipId::Clip(5, PipelineId(1, 2)),
+                    spatial_id: SpatialId(3, PipelineId(4, 5)),
+                    hit_info: None,
+                    is_backface_visible: true,
+                };
+                black_box(poke_into_described(black_box(&my_struct)));
+            })
         }),
     );
 
@@ -192,6 +911,90 @@ fn criterion_benchmark(c: &mut Criterion) {
             b.iter(|| {
                 black_box(decode_from_slice::<CommonItemProperties, _>(&bytes, config));
             })
+        })
+        .with_function("peek_poke::peek_from_checked", |b| {
+            let mut bytes = vec![
+                0u8, 0, 128, 63, 0, 0, 0, 64, 0, 0, 128, 64, 0, 0, 160, 64, 3, 0, 0, 0, 0, 0, 0, 0,
+                4, 0, 0, 0, 5, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 0, 1,
+            ];
+            // The check wants `max_size()` bytes, more than this value encodes to.
+            bytes.resize(CommonItemProperties::max_size(), 0);
+            b.iter(|| {
+                black_box(peek_from_checked::<CommonItemProperties>(
+                    black_box(&bytes),
+                    SizeLimit::Bounded(1024),
+                ));
+            })
+        })
+        .with_function("peek_poke::varint", |b| {
+            let bytes = vec![
+                0u8, 0, 128, 63, 0, 0, 0, 64, 0, 0, 128, 64, 0, 0, 160, 64, 3, 4, 5, 0, 5, 1, 2, 0,
+                1,
+            ];
+            b.iter(|| {
+                black_box(varint_peek_from(black_box(&bytes)));
+            })
+        })
+        .with_function("peek_poke::peek_from (big-endian)", |b| {
+            let bytes = vec![
+                63u8, 128, 0, 0, 64, 0, 0, 0, 64, 128, 0, 0, 64, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
+                0, 0, 0, 4, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 1, 0, 0, 0, 2, 0, 1,
+            ];
+            b.iter(|| {
+                black_box(endian_peek_from(black_box(&bytes), Endian::Big));
+            })
+        })
+        .with_function("peek_poke::bitpack", |b| {
+            let my_struct = CommonItemProperties {
+                clip_rect: Rect {
+                    point: Point { x: 1.0, y: 2.0 },
+                    size: Size { w: 4.0, h: 5.0 },
+                },
+                clip_id: ClipId::Clip(5, PipelineId(1, 2)),
+                spatial_id: SpatialId(3, PipelineId(4, 5)),
+                hit_info: None,
+                is_backface_visible: true,
+            };
+            let bytes = bitpack_poke_into(&my_struct);
+            b.iter(|| {
+                black_box(bitpack_peek_from(black_box(&bytes)));
+            })
+        })
+        .with_function("peek_poke::peek_from (display list)", |b| {
+            let items = display_list();
+            let mut encoded = Vec::new();
+            poke_vec_into(&items, &mut encoded);
+            b.iter(|| {
+                black_box(peek_vec_from(black_box(&encoded)));
+            })
+        })
+        .with_function("bincode::deserialize (display list)", |b| {
+            let config = bincode::config::Configuration::legacy();
+            let items = display_list();
+            let mut buffer = vec![0u8; 8 + items.len() * CommonItemProperties::max_size()];
+            bincode::encode_into_slice(&items, &mut buffer.as_mut_slice(), config).unwrap();
+            b.iter(|| {
+                black_box(decode_from_slice::<Vec<CommonItemProperties>, _>(
+                    black_box(&buffer),
+                    config,
+                ));
+            })
+        })
+        .with_function("peek_poke::peek_from_described", |b| {
+            let my_struct = CommonItemProperties {
+                clip_rect: Rect {
+                    point: Point { x: 1.0, y: 2.0 },
+                    size: Size { w: 4.0, h: 5.0 },
+                },
+                clip_id: ClipId::Clip(5, PipelineId(1, 2)),
+                spatial_id: SpatialId(3, PipelineId(4, 5)),
+                hit_info: None,
+                is_backface_visible: true,
+            };
+            let bytes = poke_into_described(&my_struct);
+            b.iter(|| {
+                black_box(peek_from_described(black_box(&bytes)));
+            })
         }),
     );
 }
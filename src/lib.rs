@@ -0,0 +1,287 @@
+//! Encoding primitives shared between the benchmarks and the round-trip
+//! tests, so both can exercise the same bit-packed and self-describing
+//! formats instead of keeping two copies in sync by hand.
+
+/// A bounds-checked alternative to `peek_poke::peek_from_default`: verifies
+/// the buffer holds at least `T::max_size()` bytes (and that this fits
+/// within an optional `SizeLimit`) before peeking, instead of trusting the
+/// caller not to hand over a truncated or malformed buffer.
+pub mod checked {
+    use peek_poke::PeekPoke;
+    use std::fmt;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum SizeLimit {
+        Infinite,
+        Bounded(usize),
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum PeekError {
+        /// The buffer is shorter than `T::max_size()` bytes.
+        BufferTooSmall { needed: usize, available: usize },
+        /// `T::max_size()` exceeds the caller-supplied `SizeLimit::Bounded`.
+        LimitExceeded { limit: usize, needed: usize },
+    }
+
+    impl fmt::Display for PeekError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match *self {
+                PeekError::BufferTooSmall { needed, available } => write!(
+                    f,
+                    "buffer too small to peek: needed {} bytes, only {} available",
+                    needed, available
+                ),
+                PeekError::LimitExceeded { limit, needed } => write!(
+                    f,
+                    "value exceeds configured size limit: needed {} bytes, limit is {}",
+                    needed, limit
+                ),
+            }
+        }
+    }
+
+    impl std::error::Error for PeekError {}
+
+    /// Takes a `&[u8]` instead of a raw pointer, verifies at least
+    /// `T::max_size()` bytes are present (and within `limit`) before peeking,
+    /// and returns the unconsumed remainder of the buffer alongside the
+    /// decoded value.
+    pub fn peek_from_checked<T>(buf: &[u8], limit: SizeLimit) -> Result<(T, &[u8]), PeekError>
+    where
+        T: PeekPoke + Default,
+    {
+        let needed = T::max_size();
+        if let SizeLimit::Bounded(limit) = limit {
+            if needed > limit {
+                return Err(PeekError::LimitExceeded { limit, needed });
+            }
+        }
+        if buf.len() < needed {
+            return Err(PeekError::BufferTooSmall {
+                needed,
+                available: buf.len(),
+            });
+        }
+        let mut value = T::default();
+        let end_ptr = unsafe { value.peek_from(buf.as_ptr()) };
+        let consumed = end_ptr as usize - buf.as_ptr() as usize;
+        Ok((value, &buf[consumed..]))
+    }
+}
+
+/// A dense bit stream for packing bools and small discriminants (in the
+/// spirit of bitcode's bit buffer), with `write_raw_bytes`/`read_raw_bytes`
+/// for byte-aligned fixed-width fields alongside them.
+pub mod bitpack {
+    pub struct BitWriter {
+        bytes: Vec<u8>,
+        accumulator: u64,
+        bit_count: u32,
+    }
+
+    impl BitWriter {
+        pub fn new() -> Self {
+            BitWriter {
+                bytes: Vec::new(),
+                accumulator: 0,
+                bit_count: 0,
+            }
+        }
+
+        /// Writes the low `bits` bits of `value`, least-significant bit
+        /// first, flushing full bytes to the output as they fill.
+        pub fn write_bits(&mut self, value: u64, bits: u32) {
+            // Split wide writes into <=32-bit chunks so bit_count + bits
+            // can never exceed the 64-bit accumulator.
+            if bits > 32 {
+                self.write_bits(value & 0xffff_ffff, 32);
+                self.write_bits(value >> 32, bits - 32);
+                return;
+            }
+            self.accumulator |= (value & ((1u64 << bits) - 1)) << self.bit_count;
+            self.bit_count += bits;
+            while self.bit_count >= 8 {
+                self.bytes.push(self.accumulator as u8);
+                self.accumulator >>= 8;
+                self.bit_count -= 8;
+            }
+        }
+
+        pub fn write_bool(&mut self, value: bool) {
+            self.write_bits(value as u64, 1);
+        }
+
+        /// Pads the current partial byte with zeros; a no-op if already
+        /// byte-aligned.
+        pub fn align_to_byte(&mut self) {
+            if self.bit_count > 0 {
+                self.bytes.push(self.accumulator as u8);
+                self.accumulator = 0;
+                self.bit_count = 0;
+            }
+        }
+
+        /// Aligns to a byte boundary, then copies `bytes` in directly.
+        pub fn write_raw_bytes(&mut self, bytes: &[u8]) {
+            self.align_to_byte();
+            self.bytes.extend_from_slice(bytes);
+        }
+
+        /// Pads the final partial byte with zeros and returns the packed
+        /// stream.
+        pub fn finish(mut self) -> Vec<u8> {
+            self.align_to_byte();
+            self.bytes
+        }
+    }
+
+    pub struct BitReader<'a> {
+        bytes: &'a [u8],
+        pos: usize,
+        accumulator: u64,
+        bit_count: u32,
+    }
+
+    impl<'a> BitReader<'a> {
+        pub fn new(bytes: &'a [u8]) -> Self {
+            BitReader {
+                bytes,
+                pos: 0,
+                accumulator: 0,
+                bit_count: 0,
+            }
+        }
+
+        pub fn read_bits(&mut self, bits: u32) -> u64 {
+            if bits > 32 {
+                let low = self.read_bits(32);
+                let high = self.read_bits(bits - 32);
+                return low | (high << 32);
+            }
+            while self.bit_count < bits {
+                self.accumulator |= (self.bytes[self.pos] as u64) << self.bit_count;
+                self.pos += 1;
+                self.bit_count += 8;
+            }
+            let mask = (1u64 << bits) - 1;
+            let value = self.accumulator & mask;
+            self.accumulator >>= bits;
+            self.bit_count -= bits;
+            value
+        }
+
+        pub fn read_bool(&mut self) -> bool {
+            self.read_bits(1) != 0
+        }
+
+        /// Discards any partially-consumed byte so the next read starts on
+        /// a byte boundary; a no-op if already aligned.
+        pub fn align_to_byte(&mut self) {
+            self.accumulator = 0;
+            self.bit_count = 0;
+        }
+
+        pub fn read_raw_bytes(&mut self, n: usize) -> &'a [u8] {
+            self.align_to_byte();
+            let slice = &self.bytes[self.pos..self.pos + n];
+            self.pos += n;
+            slice
+        }
+    }
+}
+
+/// A self-describing encoding in the spirit of Pot's symbol table: each
+/// field/variant name is interned once, and every occurrence (including
+/// later ones with the same name) is written as just its symbol-table
+/// index, with unrecognized fields skippable by their length prefix.
+pub mod described {
+    use std::collections::HashMap;
+
+    pub struct DescribedWriter {
+        symbols: Vec<String>,
+        symbol_lookup: HashMap<String, u8>,
+        fields: Vec<(u8, Vec<u8>)>,
+    }
+
+    impl DescribedWriter {
+        pub fn new() -> Self {
+            DescribedWriter {
+                symbols: Vec::new(),
+                symbol_lookup: HashMap::new(),
+                fields: Vec::new(),
+            }
+        }
+
+        /// Interns `name` on its first occurrence; later calls with the same
+        /// name reuse its index instead of writing the name again.
+        pub fn write_field(&mut self, name: &str, value: &[u8]) {
+            let index = match self.symbol_lookup.get(name) {
+                Some(&index) => index,
+                None => {
+                    let index = self.symbols.len() as u8;
+                    self.symbols.push(name.to_string());
+                    self.symbol_lookup.insert(name.to_string(), index);
+                    index
+                }
+            };
+            self.fields.push((index, value.to_vec()));
+        }
+
+        pub fn finish(self) -> Vec<u8> {
+            let mut buf = vec![self.symbols.len() as u8];
+            for symbol in &self.symbols {
+                buf.push(symbol.len() as u8);
+                buf.extend_from_slice(symbol.as_bytes());
+            }
+            buf.push(self.fields.len() as u8);
+            for (index, value) in &self.fields {
+                buf.push(*index);
+                buf.extend_from_slice(&(value.len() as u32).to_ne_bytes());
+                buf.extend_from_slice(value);
+            }
+            buf
+        }
+    }
+
+    pub struct DescribedReader<'a> {
+        fields: HashMap<&'a str, &'a [u8]>,
+    }
+
+    impl<'a> DescribedReader<'a> {
+        pub fn parse(bytes: &'a [u8]) -> Self {
+            let symbol_count = bytes[0];
+            let mut offset = 1;
+            let mut symbols = Vec::with_capacity(symbol_count as usize);
+            for _ in 0..symbol_count {
+                let name_len = bytes[offset] as usize;
+                offset += 1;
+                let name = std::str::from_utf8(&bytes[offset..offset + name_len]).unwrap();
+                offset += name_len;
+                symbols.push(name);
+            }
+
+            let field_count = bytes[offset];
+            offset += 1;
+            let mut fields = HashMap::with_capacity(field_count as usize);
+            for _ in 0..field_count {
+                let symbol_index = bytes[offset] as usize;
+                offset += 1;
+                let mut len_bytes = [0u8; 4];
+                len_bytes.copy_from_slice(&bytes[offset..offset + 4]);
+                let value_len = u32::from_ne_bytes(len_bytes) as usize;
+                offset += 4;
+                let value = &bytes[offset..offset + value_len];
+                offset += value_len;
+                fields.insert(symbols[symbol_index], value);
+            }
+            DescribedReader { fields }
+        }
+
+        /// `None` if the encoder didn't write this field; callers fall back
+        /// to `Default` in that case.
+        pub fn field(&self, name: &str) -> Option<&'a [u8]> {
+            self.fields.get(name).copied()
+        }
+    }
+}
@@ -24,6 +24,130 @@ where
     assert_eq!(a, b);
 }
 
+// `Vec<T>`/`String`/`Option<String>` have no static `max_size()`.
+// `UpperBound` generalizes `PeekPoke::max_size()` for them: `None` means
+// the encoded size depends on the value, so the buffer must grow as it's
+// poked instead of being pre-reserved like `poke_into` does above. Each
+// dynamic shape implements it individually (no blanket impl over
+// `PeekPoke`, which would conflict with the concrete impls below under
+// coherence, since nothing stops a foreign crate from adding `PeekPoke`
+// for `Vec<T>`/`String` later).
+trait UpperBound: Sized {
+    fn upper_bound() -> Option<usize>;
+}
+
+impl<T> UpperBound for Vec<T> {
+    fn upper_bound() -> Option<usize> {
+        None
+    }
+}
+
+impl UpperBound for String {
+    fn upper_bound() -> Option<usize> {
+        None
+    }
+}
+
+impl UpperBound for Option<String> {
+    fn upper_bound() -> Option<usize> {
+        None
+    }
+}
+
+fn new_buf<T: UpperBound>() -> Vec<u8> {
+    match T::upper_bound() {
+        Some(n) => Vec::with_capacity(n),
+        None => Vec::new(),
+    }
+}
+
+// Each dynamic shape gets its own pair of free functions instead of a
+// shared trait: a length prefix (reusing the fixed-width `u64` encoding
+// above) followed by each element/byte.
+fn poke_vec_into<T: PeekPoke>(items: &[T]) -> Vec<u8> {
+    let mut buf = new_buf::<Vec<T>>();
+    buf.extend_from_slice(&poke_into(&(items.len() as u64)));
+    for item in items {
+        buf.extend_from_slice(&poke_into(item));
+    }
+    buf
+}
+
+fn peek_vec_from<T: PeekPoke>(buf: &[u8]) -> (Vec<T>, usize) {
+    let mut len: u64 = unsafe { std::mem::uninitialized() };
+    let end_ptr = len.peek_from(buf.as_ptr());
+    let mut consumed = end_ptr as usize - buf.as_ptr() as usize;
+
+    let mut items = Vec::with_capacity(len as usize);
+    for _ in 0..len {
+        let mut item: T = unsafe { std::mem::uninitialized() };
+        let end_ptr = item.peek_from(buf[consumed..].as_ptr());
+        consumed += end_ptr as usize - buf[consumed..].as_ptr() as usize;
+        items.push(item);
+    }
+    (items, consumed)
+}
+
+fn the_same_vec<T: PartialEq + Debug + PeekPoke>(items: Vec<T>) {
+    let v = poke_vec_into(&items);
+    let (decoded, consumed) = peek_vec_from::<T>(&v);
+    assert_eq!(consumed, v.len());
+    assert_eq!(items, decoded);
+}
+
+fn poke_string_into(s: &str) -> Vec<u8> {
+    let mut buf = new_buf::<String>();
+    buf.extend_from_slice(&poke_into(&(s.len() as u64)));
+    buf.extend_from_slice(s.as_bytes());
+    buf
+}
+
+fn peek_string_from(buf: &[u8]) -> (String, usize) {
+    let mut len: u64 = unsafe { std::mem::uninitialized() };
+    let end_ptr = len.peek_from(buf.as_ptr());
+    let consumed = end_ptr as usize - buf.as_ptr() as usize;
+    let len = len as usize;
+    let s = String::from_utf8(buf[consumed..consumed + len].to_vec())
+        .expect("invalid utf-8 in peeked string");
+    (s, consumed + len)
+}
+
+fn the_same_string(s: String) {
+    let v = poke_string_into(&s);
+    let (decoded, consumed) = peek_string_from(&v);
+    assert_eq!(consumed, v.len());
+    assert_eq!(s, decoded);
+}
+
+fn poke_optional_string_into(s: &Option<String>) -> Vec<u8> {
+    let mut buf = new_buf::<Option<String>>();
+    match s {
+        Some(s) => {
+            buf.push(1);
+            buf.extend_from_slice(&poke_string_into(s));
+        }
+        None => buf.push(0),
+    }
+    buf
+}
+
+fn peek_optional_string_from(buf: &[u8]) -> (Option<String>, usize) {
+    match buf[0] {
+        0 => (None, 1),
+        _ => {
+            let (s, n) = peek_string_from(&buf[1..]);
+            (Some(s), 1 + n)
+        }
+    }
+}
+
+fn the_same_optional_string(value: Option<String>) {
+    let v = poke_optional_string_into(&value);
+    let (decoded, consumed) = peek_optional_string_from(&v);
+    assert_eq!(consumed, v.len());
+    assert_eq!(value, decoded);
+}
+
 #[test]
 fn test_numbers() {
     // unsigned positive
@@ -61,18 +185,29 @@ fn test_bool() {
 #[test]
 fn test_option() {
     the_same(Some(5usize));
-    //the_same(Some("foo bar".to_string()));
     the_same(None::<usize>);
+    the_same_optional_string(Some("foo bar".to_string()));
+    the_same_optional_string(None);
+}
+
+#[test]
+fn test_string() {
+    the_same_string("".to_string());
+    the_same_string("foo bar".to_string());
+}
+
+#[test]
+fn test_vec() {
+    the_same_vec(Vec::<u32>::new());
+    the_same_vec(vec![1u32, 2, 3, 4, 5]);
 }
 
-/*
 #[test]
 fn test_fixed_size_array() {
     the_same([24u32; 32]);
     the_same([1u64, 2, 3, 4, 5, 6, 7, 8]);
     the_same([0u8; 19]);
 }
- */
 
 #[test]
 fn test_tuple() {
@@ -170,6 +305,187 @@ fn test_generic() {
     the_same(Foo { x: 19.0, y: 42.0 });
 }
 
+#[test]
+fn test_bitpacked_fields() {
+    use peek_poke_benchs::bitpack::{BitReader, BitWriter};
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Mode {
+        Visible,
+        Hidden,
+        Collapsed,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    struct Flags {
+        is_backface_visible: bool,
+        has_hit_info: bool,
+        mode: Mode,
+    }
+
+    // `mode` has 3 variants, so it needs ceil(log2(3)) = 2 bits.
+    const MODE_BITS: u32 = 2;
+
+    fn poke(flags: Flags) -> Vec<u8> {
+        let mut w = BitWriter::new();
+        w.write_bool(flags.is_backface_visible);
+        w.write_bool(flags.has_hit_info);
+        let mode = match flags.mode {
+            Mode::Visible => 0,
+            Mode::Hidden => 1,
+            Mode::Collapsed => 2,
+        };
+        w.write_bits(mode, MODE_BITS);
+        w.finish()
+    }
+
+    fn peek(bytes: &[u8]) -> Flags {
+        let mut r = BitReader::new(bytes);
+        let is_backface_visible = r.read_bool();
+        let has_hit_info = r.read_bool();
+        let mode = match r.read_bits(MODE_BITS) {
+            0 => Mode::Visible,
+            1 => Mode::Hidden,
+            2 => Mode::Collapsed,
+            n => panic!("unexpected mode discriminant {}", n),
+        };
+        Flags {
+            is_backface_visible,
+            has_hit_info,
+            mode,
+        }
+    }
+
+    for flags in [
+        Flags {
+            is_backface_visible: true,
+            has_hit_info: false,
+            mode: Mode::Visible,
+        },
+        Flags {
+            is_backface_visible: false,
+            has_hit_info: true,
+            mode: Mode::Collapsed,
+        },
+    ] {
+        let packed = poke(flags);
+        // 4 bits of real information pad out to a single byte, versus 3
+        // bytes (one per bool/enum) if each field kept its own byte.
+        assert_eq!(packed.len(), 1);
+        assert_eq!(peek(&packed), flags);
+    }
+}
+
+// Proves the self-describing format is version-tolerant in both
+// directions: a newer encoder's extra field is skipped by an older
+// decoder, and an older encoder's missing field falls back to `Default`
+// for a newer decoder.
+#[test]
+fn test_described_version_tolerance() {
+    use peek_poke_benchs::described::{DescribedReader, DescribedWriter};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct PointV1 {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct PointV2 {
+        x: f32,
+        y: f32,
+        z: f32,
+    }
+
+    fn poke_v1(p: PointV1) -> Vec<u8> {
+        let mut w = DescribedWriter::new();
+        w.write_field("x", &p.x.to_ne_bytes());
+        w.write_field("y", &p.y.to_ne_bytes());
+        w.finish()
+    }
+
+    fn poke_v2(p: PointV2) -> Vec<u8> {
+        let mut w = DescribedWriter::new();
+        w.write_field("x", &p.x.to_ne_bytes());
+        w.write_field("y", &p.y.to_ne_bytes());
+        w.write_field("z", &p.z.to_ne_bytes());
+        w.finish()
+    }
+
+    fn peek_v1(bytes: &[u8]) -> PointV1 {
+        let r = DescribedReader::parse(bytes);
+        let field = |name| {
+            r.field(name)
+                .map(|b| f32::from_ne_bytes(b.try_into().unwrap()))
+                .unwrap_or_default()
+        };
+        PointV1 {
+            x: field("x"),
+            y: field("y"),
+        }
+    }
+
+    fn peek_v2(bytes: &[u8]) -> PointV2 {
+        let r = DescribedReader::parse(bytes);
+        let field = |name| {
+            r.field(name)
+                .map(|b| f32::from_ne_bytes(b.try_into().unwrap()))
+                .unwrap_or_default()
+        };
+        PointV2 {
+            x: field("x"),
+            y: field("y"),
+            z: field("z"),
+        }
+    }
+
+    // Forward compatibility: a newer encoder's extra "z" field is ignored
+    // by an older decoder that only looks up "x" and "y".
+    let v2 = PointV2 { x: 1.0, y: 2.0, z: 3.0 };
+    let decoded_as_v1 = peek_v1(&poke_v2(v2));
+    assert_eq!(decoded_as_v1, PointV1 { x: 1.0, y: 2.0 });
+
+    // Backward compatibility: an older encoder's stream has no "z" field,
+    // so a newer decoder falls back to `Default` (0.0) for it.
+    let v1 = PointV1 { x: 4.0, y: 5.0 };
+    let decoded_as_v2 = peek_v2(&poke_v1(v1));
+    assert_eq!(decoded_as_v2, PointV2 { x: 4.0, y: 5.0, z: 0.0 });
+}
+
+#[test]
+fn test_peek_from_checked() {
+    use peek_poke_benchs::checked::{peek_from_checked, PeekError, SizeLimit};
+
+    #[derive(Debug, Default, PartialEq, PeekPoke)]
+    struct Pair {
+        a: u32,
+        b: u32,
+    }
+
+    let bytes = poke_into(&Pair { a: 1, b: 2 });
+
+    let (value, rest) = peek_from_checked::<Pair>(&bytes, SizeLimit::Infinite).unwrap();
+    assert_eq!(value, Pair { a: 1, b: 2 });
+    assert!(rest.is_empty());
+
+    let too_short = &bytes[..bytes.len() - 1];
+    assert_eq!(
+        peek_from_checked::<Pair>(too_short, SizeLimit::Infinite),
+        Err(PeekError::BufferTooSmall {
+            needed: Pair::max_size(),
+            available: too_short.len(),
+        })
+    );
+
+    assert_eq!(
+        peek_from_checked::<Pair>(&bytes, SizeLimit::Bounded(Pair::max_size() - 1)),
+        Err(PeekError::LimitExceeded {
+            limit: Pair::max_size() - 1,
+            needed: Pair::max_size(),
+        })
+    );
+}
+
 #[cfg(feature = "extras")]
 mod extra_tests {
     use super::*;